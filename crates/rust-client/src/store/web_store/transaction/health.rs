@@ -0,0 +1,184 @@
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use miden_objects::{Digest, account::AccountId, transaction::OutputNotes};
+use miden_tx::utils::Deserializable;
+use serde_wasm_bindgen::from_value;
+use wasm_bindgen_futures::JsFuture;
+
+use super::{WebStore, js_bindings::idxdb_get_transactions, models::TransactionIdxdbObject};
+use crate::store::StoreError;
+
+/// The specific way a stored transaction row failed validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionDefectKind {
+    InvalidId,
+    InvalidAccountId,
+    InvalidInitAccountState,
+    InvalidFinalAccountState,
+    InvalidBlockNum,
+    InvalidCommitHeight,
+    InvalidInputNotes,
+    InvalidOutputNotes,
+    MissingTransactionScript,
+    InvalidTransactionScript,
+    AccountStateChainMismatch,
+}
+
+/// A single integrity problem found while auditing the transaction table, keyed by the
+/// row's raw `id` string since the id itself may be one of the things that's malformed.
+#[derive(Debug, Clone)]
+pub struct TransactionDefect {
+    pub transaction_id: String,
+    pub defect: TransactionDefectKind,
+}
+
+/// Report produced by [`WebStore::check_health`].
+#[derive(Debug, Clone, Default)]
+pub struct StoreHealth {
+    pub transactions_checked: usize,
+    pub defects: Vec<TransactionDefect>,
+}
+
+impl StoreHealth {
+    pub fn is_healthy(&self) -> bool {
+        self.defects.is_empty()
+    }
+}
+
+impl WebStore {
+    /// Audits the transaction table for internal consistency instead of trusting the raw
+    /// idxdb rows the way [`Self::get_transactions`] does. Every row is fully validated and
+    /// malformed data is recorded as a [`TransactionDefect`] rather than panicking (the
+    /// `parse::<u32>().unwrap()` and `expect(..)` calls `get_transactions` relies on).
+    ///
+    /// Checks performed per row: `id`, `account_id`, `init_account_state` and
+    /// `final_account_state` decode; `block_num`/`commit_height` parse; `input_notes` and
+    /// `output_notes` deserialize; a row with a `script_root` carries a decodable
+    /// `TransactionScript`. Across rows: every committed transaction's `init_account_state`
+    /// must match the previous committed transaction's `final_account_state` for the same
+    /// account.
+    pub async fn check_health(&self) -> Result<StoreHealth, StoreError> {
+        let promise = idxdb_get_transactions("All".to_string());
+        let js_value = JsFuture::from(promise).await.map_err(|js_error| {
+            StoreError::DatabaseError(format!("failed to get transactions: {js_error:?}"))
+        })?;
+        let rows: Vec<TransactionIdxdbObject> = from_value(js_value)
+            .map_err(|err| StoreError::DatabaseError(format!("failed to deserialize {err:?}")))?;
+
+        let mut defects = Vec::new();
+        // account_id -> (commit_height, transaction id, final_account_state) of every row
+        // that passed its own per-row checks and is committed, used for the cross-row chain
+        // check below. Keyed by commit_height (not block_num, which is the tx's expiration
+        // height and unrelated to chain order) and carries the id so a row never matches
+        // itself as its own predecessor.
+        let mut committed_by_account: BTreeMap<String, Vec<(u32, String, Digest)>> = BTreeMap::new();
+
+        for row in &rows {
+            let mut defect = |kind: TransactionDefectKind| TransactionDefect {
+                transaction_id: row.id.clone(),
+                defect: kind,
+            };
+            let mut row_is_valid = true;
+
+            if Digest::try_from(row.id.clone()).is_err() {
+                defects.push(defect(TransactionDefectKind::InvalidId));
+                row_is_valid = false;
+            }
+            if AccountId::from_hex(&row.account_id).is_err() {
+                defects.push(defect(TransactionDefectKind::InvalidAccountId));
+                row_is_valid = false;
+            }
+            if Digest::try_from(row.init_account_state.clone()).is_err() {
+                defects.push(defect(TransactionDefectKind::InvalidInitAccountState));
+                row_is_valid = false;
+            }
+            let final_account_state = Digest::try_from(row.final_account_state.clone());
+            if final_account_state.is_err() {
+                defects.push(defect(TransactionDefectKind::InvalidFinalAccountState));
+                row_is_valid = false;
+            }
+            let block_num = row.block_num.parse::<u32>();
+            if block_num.is_err() {
+                defects.push(defect(TransactionDefectKind::InvalidBlockNum));
+                row_is_valid = false;
+            }
+            let commit_height = row.commit_height.as_deref().map(str::parse::<u32>).transpose();
+            if commit_height.is_err() {
+                defects.push(defect(TransactionDefectKind::InvalidCommitHeight));
+                row_is_valid = false;
+            }
+            if Vec::<Digest>::read_from_bytes(&row.input_notes).is_err() {
+                defects.push(defect(TransactionDefectKind::InvalidInputNotes));
+                row_is_valid = false;
+            }
+            if OutputNotes::read_from_bytes(&row.output_notes).is_err() {
+                defects.push(defect(TransactionDefectKind::InvalidOutputNotes));
+                row_is_valid = false;
+            }
+            if row.script_root.is_some() {
+                use miden_objects::transaction::TransactionScript;
+                match row.tx_script.as_ref().map(|bytes| TransactionScript::read_from_bytes(bytes)) {
+                    Some(Ok(_)) => {},
+                    Some(Err(_)) => {
+                        defects.push(defect(TransactionDefectKind::InvalidTransactionScript));
+                        row_is_valid = false;
+                    },
+                    None => {
+                        defects.push(defect(TransactionDefectKind::MissingTransactionScript));
+                        row_is_valid = false;
+                    },
+                }
+            }
+
+            if row_is_valid {
+                if let (Ok(Some(commit_height)), Ok(final_account_state)) =
+                    (commit_height, final_account_state)
+                {
+                    committed_by_account.entry(row.account_id.clone()).or_default().push((
+                        commit_height,
+                        row.id.clone(),
+                        final_account_state,
+                    ));
+                }
+            }
+        }
+
+        for row in &rows {
+            let Some(commit_height) =
+                row.commit_height.as_deref().and_then(|height| height.parse::<u32>().ok())
+            else {
+                continue;
+            };
+            let Ok(init_account_state) = Digest::try_from(row.init_account_state.clone()) else {
+                continue;
+            };
+            let Some(history) = committed_by_account.get(&row.account_id) else { continue };
+
+            // The immediate predecessor is the committed transaction with the highest
+            // commit_height strictly below this row's, excluding the row itself.
+            let predecessor_final_state = history
+                .iter()
+                .filter(|(other_commit_height, other_id, _)| {
+                    *other_commit_height < commit_height && *other_id != row.id
+                })
+                .max_by_key(|(other_commit_height, _, _)| *other_commit_height)
+                .map(|(_, _, state)| *state);
+
+            if let Some(predecessor_final_state) = predecessor_final_state {
+                if predecessor_final_state != init_account_state {
+                    defects.push(TransactionDefect {
+                        transaction_id: row.id.clone(),
+                        defect: TransactionDefectKind::AccountStateChainMismatch,
+                    });
+                }
+            }
+        }
+
+        Ok(StoreHealth { transactions_checked: rows.len(), defects })
+    }
+}
@@ -0,0 +1,213 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use miden_objects::{
+    Digest,
+    account::{Account, AccountId},
+    block::BlockNumber,
+    transaction::{OutputNotes, TransactionScript},
+};
+use miden_tx::utils::{ByteReader, Deserializable, DeserializationError, Serializable, SliceReader};
+use serde::Serialize;
+use serde_wasm_bindgen::to_value;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+use super::{WebStore, account::utils::update_account, off_chain};
+use crate::{
+    store::{StoreError, TransactionFilter},
+    transaction::{TransactionRecord, TransactionStatus},
+};
+
+#[wasm_bindgen(module = "/js/db/transactions.js")]
+extern "C" {
+    #[wasm_bindgen(js_name = idxdbInsertTransactionRecord)]
+    fn idxdb_insert_transaction_record(row: JsValue) -> js_sys::Promise;
+}
+
+/// Wire format for a [`TransactionRecord`] exported by another client instance: the same
+/// fields `get_transactions` reconstructs, encoded by sequentially writing each with the
+/// `Serializable`/`Deserializable` impls the rest of the store already relies on, plus the
+/// account's resulting full state and the transaction's status, so importing can actually
+/// apply both rather than just writing a row and hoping a later sync fills them in.
+struct ImportedTransaction {
+    id: Digest,
+    account_id: AccountId,
+    init_account_state: Digest,
+    updated_account: Account,
+    input_note_nullifiers: Vec<Digest>,
+    output_notes: OutputNotes,
+    block_num: BlockNumber,
+    transaction_script: Option<TransactionScript>,
+    transaction_status: TransactionStatus,
+}
+
+impl ImportedTransaction {
+    fn read_from_bytes(bytes: &[u8]) -> Result<Self, StoreError> {
+        let mut reader = SliceReader::new(bytes);
+
+        let id = Digest::read_from(&mut reader)?;
+        let account_id = AccountId::read_from(&mut reader)?;
+        let init_account_state = Digest::read_from(&mut reader)?;
+        let updated_account = Account::read_from(&mut reader)?;
+        let input_note_nullifiers = Vec::<Digest>::read_from(&mut reader)?;
+        let output_notes = OutputNotes::read_from(&mut reader)?;
+        let block_num: BlockNumber = reader.read_u32()?.into();
+        let transaction_script =
+            reader.read_bool()?.then(|| TransactionScript::read_from(&mut reader)).transpose()?;
+        let transaction_status = match reader.read_u8()? {
+            0 => TransactionStatus::Pending,
+            1 => TransactionStatus::Committed(reader.read_u32()?.into()),
+            2 => TransactionStatus::Discarded,
+            tag => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid transaction status tag {tag}"
+                ))
+                .into());
+            },
+        };
+
+        Ok(Self {
+            id,
+            account_id,
+            init_account_state,
+            updated_account,
+            input_note_nullifiers,
+            output_notes,
+            block_num,
+            transaction_script,
+            transaction_status,
+        })
+    }
+}
+
+/// The idxdb row an import writes, matching the shape
+/// [`super::utils::insert_proven_transaction_data`] writes for a locally-produced
+/// transaction. `discarded` is deliberately absent: that flag is off-chain, user/UI-owned
+/// state (see [`off_chain`]), so a `Discarded` import sets it through
+/// [`off_chain::set_discarded`] instead of writing it here.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionRecordRow {
+    id: String,
+    account_id: String,
+    init_account_state: String,
+    final_account_state: String,
+    input_notes: Vec<u8>,
+    output_notes: Vec<u8>,
+    script_root: Option<String>,
+    tx_script: Option<Vec<u8>>,
+    block_num: String,
+    commit_height: Option<String>,
+}
+
+impl WebStore {
+    /// Imports a [`TransactionRecord`] exported by another client instance, applying its
+    /// account update the same way [`Self::apply_transaction`] does and writing the idxdb
+    /// row that [`super::utils::insert_proven_transaction_data`] would have written for it.
+    ///
+    /// The import is idempotent: if a transaction with the same `id` is already present it
+    /// is left untouched. If this store holds any transactions for the account, the
+    /// imported transaction's `init_account_state` must equal the `final_account_state` of
+    /// one of them; whether that transaction is the account's most recent one, or whether
+    /// the state matches the stored account's current commitment, is not checked. None of
+    /// its `input_note_nullifiers` may already belong to a committed transaction. An import
+    /// that fails either check is rejected.
+    ///
+    /// Unlike `apply_transaction`, this does not call `apply_note_updates_tx`: that takes a
+    /// `NoteUpdates` built from full input/output note records (inclusion proofs, metadata,
+    /// and all), and the wire bundle only carries nullifiers and bare `OutputNotes` — enough
+    /// to validate and display the transaction, not enough to update note-spendability
+    /// state. Note state for imported notes is expected to catch up on the next sync.
+    pub async fn import_transaction(&self, bytes: &[u8]) -> Result<(), StoreError> {
+        let imported = ImportedTransaction::read_from_bytes(bytes)?;
+        let id = imported.id.into();
+
+        let existing = self.get_transactions(TransactionFilter::All).await?;
+
+        if existing.iter().any(|record| record.id == id) {
+            // Already present locally; importing the same bundle twice is a no-op rather
+            // than an error so transferring overlapping history between profiles is safe.
+            return Ok(());
+        }
+
+        let account_transactions: Vec<&TransactionRecord> = existing
+            .iter()
+            .filter(|record| record.account_id == imported.account_id)
+            .collect();
+
+        let chains_from_stored_state = account_transactions.is_empty()
+            || account_transactions
+                .iter()
+                .any(|record| record.final_account_state == imported.init_account_state);
+
+        if !chains_from_stored_state {
+            return Err(StoreError::DatabaseError(format!(
+                "imported transaction {id} does not chain against the stored state of account {}",
+                imported.account_id
+            )));
+        }
+
+        let already_committed_nullifiers = existing
+            .iter()
+            .filter(|record| matches!(record.transaction_status, TransactionStatus::Committed(_)))
+            .flat_map(|record| record.input_note_nullifiers.iter());
+
+        for nullifier in already_committed_nullifiers {
+            if imported.input_note_nullifiers.contains(nullifier) {
+                return Err(StoreError::DatabaseError(format!(
+                    "imported transaction {id} spends a nullifier that is already committed"
+                )));
+            }
+        }
+
+        let final_account_state = imported.updated_account.commitment();
+
+        let (commit_height, discarded) = match imported.transaction_status {
+            TransactionStatus::Pending => (None, false),
+            TransactionStatus::Committed(block_num) => {
+                (Some(block_num.as_u32().to_string()), false)
+            },
+            TransactionStatus::Discarded => (None, true),
+        };
+
+        let row = TransactionRecordRow {
+            id: imported.id.to_string(),
+            account_id: imported.account_id.to_string(),
+            init_account_state: imported.init_account_state.to_string(),
+            final_account_state: final_account_state.to_string(),
+            input_notes: imported.input_note_nullifiers.to_bytes(),
+            output_notes: imported.output_notes.to_bytes(),
+            script_root: imported
+                .transaction_script
+                .as_ref()
+                .map(|script| script.root().to_string()),
+            tx_script: imported.transaction_script.as_ref().map(Serializable::to_bytes),
+            block_num: imported.block_num.as_u32().to_string(),
+            commit_height,
+        };
+
+        // Account Data — applied the same way `apply_transaction` applies a locally
+        // produced transaction's account update.
+        update_account(&imported.updated_account).await.map_err(|err| {
+            StoreError::DatabaseError(format!("failed to update account: {err:?}"))
+        })?;
+
+        let js_row = to_value(&row).map_err(|err| {
+            StoreError::DatabaseError(format!("failed to serialize imported row: {err:?}"))
+        })?;
+
+        JsFuture::from(idxdb_insert_transaction_record(js_row)).await.map_err(|js_error| {
+            StoreError::DatabaseError(format!("failed to import transaction: {js_error:?}"))
+        })?;
+
+        if discarded {
+            off_chain::set_discarded(id, true).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -25,6 +25,14 @@ use js_bindings::idxdb_get_transactions;
 mod models;
 use models::TransactionIdxdbObject;
 
+mod health;
+pub use health::{StoreHealth, TransactionDefect, TransactionDefectKind};
+
+mod import;
+mod off_chain;
+mod revert;
+mod subscribe;
+
 pub mod utils;
 use utils::insert_proven_transaction_data;
 
@@ -53,58 +61,18 @@ impl WebStore {
         let transactions_idxdb: Vec<TransactionIdxdbObject> = from_value(js_value)
             .map_err(|err| StoreError::DatabaseError(format!("failed to deserialize {err:?}")))?;
 
-        let transaction_records: Result<Vec<TransactionRecord>, StoreError> = transactions_idxdb
+        // `discarded` is off-chain, user/UI-owned state (see the `off_chain` module), so it
+        // is joined in here rather than trusted from the on-chain row.
+        let discarded_flags = off_chain::get_discarded_flags().await?;
+
+        transactions_idxdb
             .into_iter()
             .map(|tx_idxdb| {
-                let native_account_id = AccountId::from_hex(&tx_idxdb.account_id)?;
-                let block_num: BlockNumber = tx_idxdb.block_num.parse::<u32>().unwrap().into();
-                let commit_height: Option<BlockNumber> =
-                    tx_idxdb.commit_height.map(|height| height.parse::<u32>().unwrap().into());
-
-                let id: Digest = tx_idxdb.id.try_into()?;
-                let init_account_state: Digest = tx_idxdb.init_account_state.try_into()?;
-
-                let final_account_state: Digest = tx_idxdb.final_account_state.try_into()?;
-
-                let input_note_nullifiers: Vec<Digest> =
-                    Vec::<Digest>::read_from_bytes(&tx_idxdb.input_notes)?;
-
-                let output_notes = OutputNotes::read_from_bytes(&tx_idxdb.output_notes)?;
-
-                let transaction_script: Option<TransactionScript> =
-                    if tx_idxdb.script_root.is_some() {
-                        let tx_script = tx_idxdb
-                            .tx_script
-                            .map(|script| TransactionScript::read_from_bytes(&script))
-                            .transpose()?
-                            .expect("Transaction script should be included in the row");
-
-                        Some(tx_script)
-                    } else {
-                        None
-                    };
-
-                let transaction_status = match (commit_height, tx_idxdb.discarded) {
-                    (_, true) => TransactionStatus::Discarded,
-                    (Some(block_num), false) => TransactionStatus::Committed(block_num),
-                    (None, false) => TransactionStatus::Pending,
-                };
-
-                Ok(TransactionRecord {
-                    id: id.into(),
-                    account_id: native_account_id,
-                    init_account_state,
-                    final_account_state,
-                    input_note_nullifiers,
-                    output_notes,
-                    transaction_script,
-                    block_num,
-                    transaction_status,
-                })
+                let id: Digest = tx_idxdb.id.clone().try_into()?;
+                let discarded = discarded_flags.get(&id.into()).copied().unwrap_or(false);
+                decode_transaction_row(tx_idxdb, discarded)
             })
-            .collect();
-
-        transaction_records
+            .collect()
     }
 
     pub async fn apply_transaction(
@@ -122,10 +90,60 @@ impl WebStore {
         // Updates for notes
         apply_note_updates_tx(tx_update.note_updates()).await?;
 
-        for tag_record in tx_update.new_tags() {
-            self.add_note_tag(*tag_record).await?;
-        }
+        // New tags are off-chain, user-facing metadata, so they're written through the
+        // off-chain store rather than alongside the authoritative on-chain row above.
+        off_chain::add_note_tags(tx_update.new_tags()).await?;
 
         Ok(())
     }
 }
+
+/// Decodes a single raw idxdb row into a [`TransactionRecord`], joining in the
+/// already-resolved off-chain `discarded` flag. Shared by [`WebStore::get_transactions`]
+/// and the bounded poller in [`subscribe`], so the two never drift apart on row shape.
+pub(super) fn decode_transaction_row(
+    tx_idxdb: TransactionIdxdbObject,
+    discarded: bool,
+) -> Result<TransactionRecord, StoreError> {
+    let native_account_id = AccountId::from_hex(&tx_idxdb.account_id)?;
+    let block_num: BlockNumber = tx_idxdb.block_num.parse::<u32>().unwrap().into();
+    let commit_height: Option<BlockNumber> =
+        tx_idxdb.commit_height.map(|height| height.parse::<u32>().unwrap().into());
+
+    let id: Digest = tx_idxdb.id.try_into()?;
+    let init_account_state: Digest = tx_idxdb.init_account_state.try_into()?;
+    let final_account_state: Digest = tx_idxdb.final_account_state.try_into()?;
+
+    let input_note_nullifiers: Vec<Digest> = Vec::<Digest>::read_from_bytes(&tx_idxdb.input_notes)?;
+    let output_notes = OutputNotes::read_from_bytes(&tx_idxdb.output_notes)?;
+
+    let transaction_script: Option<TransactionScript> = if tx_idxdb.script_root.is_some() {
+        let tx_script = tx_idxdb
+            .tx_script
+            .map(|script| TransactionScript::read_from_bytes(&script))
+            .transpose()?
+            .expect("Transaction script should be included in the row");
+
+        Some(tx_script)
+    } else {
+        None
+    };
+
+    let transaction_status = match (commit_height, discarded) {
+        (_, true) => TransactionStatus::Discarded,
+        (Some(block_num), false) => TransactionStatus::Committed(block_num),
+        (None, false) => TransactionStatus::Pending,
+    };
+
+    Ok(TransactionRecord {
+        id: id.into(),
+        account_id: native_account_id,
+        init_account_state,
+        final_account_state,
+        input_note_nullifiers,
+        output_notes,
+        transaction_script,
+        block_num,
+        transaction_status,
+    })
+}
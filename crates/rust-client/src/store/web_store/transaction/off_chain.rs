@@ -0,0 +1,84 @@
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use serde::Deserialize;
+use serde_wasm_bindgen::{from_value, to_value};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::{note::NoteTagRecord, store::StoreError, transaction::TransactionId};
+
+/// Sync-driven writes (account commitments, note commit heights, nullifier state) are
+/// authoritative chain data and live in the "on-chain" object stores read/written
+/// elsewhere in this module. User/off-chain metadata -- note tags added via
+/// `add_note_tag`, local labels, and a transaction's discard flag -- lives here instead,
+/// in its own object-store set. Apart from the helpers in this module, the only writer of
+/// that set is the batch revert in [`super::revert`], which flips discard flags in the
+/// same idb transaction as its on-chain restores. Keeping the two apart
+/// means a future sync worker can rewrite on-chain data (including reverting orphaned
+/// transactions, see [`super::revert`]) without racing a concurrent UI edit, and makes
+/// it possible to wipe and rebuild on-chain state from the node while preserving
+/// everything recorded here.
+#[wasm_bindgen(module = "/js/db/transactions.js")]
+extern "C" {
+    #[wasm_bindgen(js_name = idxdbGetOffChainTransactionFlags)]
+    fn idxdb_get_off_chain_transaction_flags() -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = idxdbAddNoteTag)]
+    fn idxdb_add_note_tag(tag: JsValue) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = idxdbSetTransactionDiscarded)]
+    fn idxdb_set_transaction_discarded(id: String, discarded: bool) -> js_sys::Promise;
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionFlagsIdxdbObject {
+    id: String,
+    discarded: bool,
+}
+
+/// Reads the off-chain `discarded` flag for every transaction that has one recorded,
+/// keyed by transaction id. Transactions with no row here are implicitly not discarded.
+pub(super) async fn get_discarded_flags() -> Result<BTreeMap<TransactionId, bool>, StoreError> {
+    let js_value = JsFuture::from(idxdb_get_off_chain_transaction_flags()).await.map_err(
+        |js_error| StoreError::DatabaseError(format!("failed to get off-chain flags: {js_error:?}")),
+    )?;
+    let rows: Vec<TransactionFlagsIdxdbObject> = from_value(js_value)
+        .map_err(|err| StoreError::DatabaseError(format!("failed to deserialize {err:?}")))?;
+
+    rows.into_iter()
+        .map(|row| {
+            let id: TransactionId = row.id.parse()?;
+            Ok((id, row.discarded))
+        })
+        .collect()
+}
+
+/// Sets the off-chain `discarded` flag for `id`. This is a single-row write (unlike
+/// [`super::revert::revert_transactions`]'s batch, which must flip the flag atomically
+/// alongside the account/note state it restores), so there's no atomicity requirement
+/// tying it to another idb transaction here.
+pub(super) async fn set_discarded(id: TransactionId, discarded: bool) -> Result<(), StoreError> {
+    JsFuture::from(idxdb_set_transaction_discarded(id.to_string(), discarded)).await.map_err(
+        |js_error| StoreError::DatabaseError(format!("failed to set discarded flag: {js_error:?}")),
+    )?;
+    Ok(())
+}
+
+/// Records tags produced by a transaction in the off-chain store, the same path a direct
+/// call to `add_note_tag` would use.
+pub(super) async fn add_note_tags(tags: &[NoteTagRecord]) -> Result<(), StoreError> {
+    for tag in tags {
+        let js_tag = to_value(tag)
+            .map_err(|err| StoreError::DatabaseError(format!("failed to serialize tag: {err:?}")))?;
+        JsFuture::from(idxdb_add_note_tag(js_tag)).await.map_err(|js_error| {
+            StoreError::DatabaseError(format!("failed to add note tag: {js_error:?}"))
+        })?;
+    }
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::cmp::Reverse;
+
+use serde::Serialize;
+use serde_wasm_bindgen::to_value;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+use super::WebStore;
+use crate::{
+    store::{StoreError, TransactionFilter},
+    transaction::{TransactionId, TransactionRecord},
+};
+
+#[wasm_bindgen(module = "/js/db/transactions.js")]
+extern "C" {
+    #[wasm_bindgen(js_name = idxdbRevertTransactions)]
+    fn idxdb_revert_transactions(reverts: JsValue) -> js_sys::Promise;
+}
+
+/// A single transaction's worth of idxdb writes needed to undo it: restore the account to
+/// its pre-transaction state, drop the notes it produced, re-arm the notes it consumed, and
+/// flip its own status to `Discarded`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionRevertRow {
+    id: String,
+    account_id: String,
+    restored_account_state: String,
+    output_note_ids: Vec<String>,
+    reclaimed_nullifiers: Vec<String>,
+}
+
+/// Orders `records` so that a transaction is reverted only after every transaction that
+/// chains off of it (i.e. whose `init_account_state` equals its `final_account_state`).
+/// Reverting in this order means the last write to an account's state is always the
+/// oldest reverted transaction, so the account ends up at the correct common ancestor
+/// state instead of being clobbered by a later revert.
+fn reverse_dependency_order(records: &[TransactionRecord]) -> Vec<TransactionId> {
+    let predecessor_of: BTreeMap<TransactionId, TransactionId> = records
+        .iter()
+        .filter_map(|candidate| {
+            let predecessor = records.iter().find(|other| {
+                other.account_id == candidate.account_id
+                    && other.final_account_state == candidate.init_account_state
+            })?;
+            Some((candidate.id, predecessor.id))
+        })
+        .collect();
+
+    // Walks the predecessor chain iteratively, tracking visited ids so a cycle (e.g. a
+    // corrupted chain, or a record whose final_account_state happens to equal its own
+    // init_account_state) terminates instead of recursing forever. A cycle is treated as
+    // depth 0 for every id in it: none of them can be ordered relative to each other, and
+    // this keeps the sort well-defined rather than panicking on otherwise-malformed input.
+    fn depth(id: TransactionId, predecessor_of: &BTreeMap<TransactionId, TransactionId>) -> usize {
+        let mut visited = BTreeSet::new();
+        let mut current = id;
+        let mut steps = 0;
+
+        while let Some(&predecessor) = predecessor_of.get(&current) {
+            if !visited.insert(current) {
+                return 0;
+            }
+            current = predecessor;
+            steps += 1;
+        }
+
+        steps
+    }
+
+    let mut ordered: Vec<TransactionId> = records.iter().map(|record| record.id).collect();
+    ordered.sort_by_key(|id| Reverse(depth(*id, &predecessor_of)));
+    ordered
+}
+
+impl WebStore {
+    /// Reverts `ids`, restoring each transaction's account to its stored
+    /// `init_account_state`, un-committing the output notes it produced (removing them if
+    /// they only existed because of this transaction, clearing their commit height
+    /// otherwise), re-arming its consumed `input_note_nullifiers` as spendable, and marking
+    /// it `Discarded`. This is the inverse of [`Self::apply_transaction`], used to roll back
+    /// transactions orphaned by a chain reorg.
+    ///
+    /// The batch is processed in reverse dependency order, so a transaction is reverted
+    /// only after every transaction chained off of it, keeping account-state rollback
+    /// consistent. All of the underlying idxdb writes happen in a single transaction, so a
+    /// failure partway through can't leave the store in an inconsistent state.
+    pub async fn revert_transactions(&self, ids: &[TransactionId]) -> Result<(), StoreError> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let records = self.get_transactions(TransactionFilter::Ids(ids.to_vec())).await?;
+        let by_id: BTreeMap<TransactionId, &TransactionRecord> =
+            records.iter().map(|record| (record.id, record)).collect();
+
+        let rows: Vec<TransactionRevertRow> = reverse_dependency_order(&records)
+            .into_iter()
+            .map(|id| {
+                let record = by_id.get(&id).expect("id was sourced from records");
+                TransactionRevertRow {
+                    id: record.id.to_string(),
+                    account_id: record.account_id.to_string(),
+                    restored_account_state: record.init_account_state.to_string(),
+                    output_note_ids: record
+                        .output_notes
+                        .iter()
+                        .map(|note| note.id().to_string())
+                        .collect(),
+                    reclaimed_nullifiers: record
+                        .input_note_nullifiers
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect(),
+                }
+            })
+            .collect();
+
+        let js_rows = to_value(&rows)
+            .map_err(|err| StoreError::DatabaseError(format!("failed to serialize reverts: {err:?}")))?;
+
+        // `idxdbRevertTransactions` opens a single idb transaction spanning both the
+        // on-chain and off-chain (see `super::off_chain`) object stores, restoring each
+        // row's account/note state *and* flipping its `Discarded` flag together, so a
+        // failure partway through can't leave a transaction's status out of sync with its
+        // restored account/note state.
+        let promise = idxdb_revert_transactions(js_rows);
+        JsFuture::from(promise).await.map_err(|js_error| {
+            StoreError::DatabaseError(format!("failed to revert transactions: {js_error:?}"))
+        })?;
+
+        Ok(())
+    }
+}